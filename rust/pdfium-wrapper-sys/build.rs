@@ -8,9 +8,29 @@ fn main() {
     //   1. PDFIUM_LIB_DIR environment variable  (explicit override)
     //   2. Auto-detect from PDFIUM_ROOT + Cargo target triple
 
-    let lib_dir = if let Ok(dir) = env::var("PDFIUM_LIB_DIR") {
+    let explicit_lib_dir = env::var("PDFIUM_LIB_DIR");
+    let explicit_root = env::var("PDFIUM_ROOT");
+
+    // ── System-library fallback ──────────────────────────────────────────
+    //
+    // When neither bundle location is configured, `PDFIUM_USE_SYSTEM_LIB=1`
+    // skips the bundled static `pdfium_wrapper` and links a system-installed
+    // `libpdfium` via the OS loader path, mirroring the high-level crates'
+    // "bind to system library" mode.
+    if explicit_lib_dir.is_err()
+        && explicit_root.is_err()
+        && env::var("PDFIUM_USE_SYSTEM_LIB").as_deref() == Ok("1")
+    {
+        println!("cargo:rustc-link-lib=dylib=pdfium");
+        println!("cargo:rerun-if-env-changed=PDFIUM_LIB_DIR");
+        println!("cargo:rerun-if-env-changed=PDFIUM_ROOT");
+        println!("cargo:rerun-if-env-changed=PDFIUM_USE_SYSTEM_LIB");
+        return;
+    }
+
+    let lib_dir = if let Ok(dir) = explicit_lib_dir {
         PathBuf::from(dir)
-    } else if let Ok(root) = env::var("PDFIUM_ROOT") {
+    } else if let Ok(root) = explicit_root {
         let target = env::var("TARGET").unwrap();
         let subdir = target_to_subdir(&target);
         PathBuf::from(root).join(subdir).join("lib")
@@ -41,6 +61,7 @@ fn main() {
     // Re-run if the env vars change.
     println!("cargo:rerun-if-env-changed=PDFIUM_LIB_DIR");
     println!("cargo:rerun-if-env-changed=PDFIUM_ROOT");
+    println!("cargo:rerun-if-env-changed=PDFIUM_USE_SYSTEM_LIB");
 }
 
 /// Map a Cargo target triple to the subdirectory inside the pdf-engine bundle.
@@ -57,6 +78,13 @@ fn target_to_subdir(target: &str) -> &'static str {
         "x86_64-apple-ios" => "ios/x86_64",
         "aarch64-apple-ios-sim" => "ios/x86_64", // arm64 simulator uses same libs
 
+        // Desktop
+        "x86_64-unknown-linux-gnu" => "linux/x64",
+        "aarch64-unknown-linux-gnu" => "linux/arm64",
+        "x86_64-pc-windows-msvc" => "windows/x64",
+        "x86_64-apple-darwin" => "macos/x64",
+        "aarch64-apple-darwin" => "macos/arm64",
+
         _ => panic!(
             "pdfium-wrapper-sys: unsupported target '{}'. \
              Set PDFIUM_LIB_DIR manually.",
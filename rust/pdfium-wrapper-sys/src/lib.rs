@@ -19,20 +19,67 @@
 
 use std::os::raw::{c_char, c_int, c_uchar, c_void};
 
-/// RGBA bitmap returned by [`pdfium_render_page`].
+/// Output pixel layout selected via [`PdfiumRenderConfig`].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PdfiumPixelFormat {
+    /// 8 bits per channel, red-green-blue-alpha order (4 bytes per pixel).
+    Rgba8888 = 0,
+    /// 8 bits per channel, blue-green-red-alpha order (4 bytes per pixel).
+    /// The native layout for most GPU/texture-upload paths.
+    Bgra8888 = 1,
+    /// 8-bit grayscale (1 byte per pixel).
+    Gray8 = 2,
+}
+
+/// Pixel buffer returned by [`pdfium_render_page`] / [`pdfium_render_page_ex`].
 ///
 /// The `data` pointer is owned by the C side — free it with
 /// [`pdfium_free_bitmap`].
 #[repr(C)]
 pub struct PdfiumBitmap {
-    /// RGBA pixel buffer (width × height × 4 bytes).
+    /// Pixel buffer; its layout is described by `stride` and the requested
+    /// [`PdfiumPixelFormat`].
     pub data: *mut c_uchar,
     /// Bitmap width in pixels.
     pub width: c_int,
     /// Bitmap height in pixels.
     pub height: c_int,
-    /// Bytes per row (`width * 4` for RGBA).
+    /// Bytes per row (`width * 4` for the 32-bit formats, `width` for
+    /// [`PdfiumPixelFormat::Gray8`]).
     pub stride: c_int,
+    /// Whether the alpha channel carries meaningful data.  PDFium
+    /// premultiplies alpha when rendering onto a transparent background, so
+    /// when this is `true` the color channels are premultiplied.  Always
+    /// `false` for [`PdfiumPixelFormat::Gray8`].
+    pub has_alpha: bool,
+}
+
+/// Rendering options for [`pdfium_render_page_ex`].
+///
+/// `pdfium_render_page` is equivalent to calling the extended function with
+/// `target_width` set and every other field left at its default (zero /
+/// `false`).
+#[repr(C)]
+pub struct PdfiumRenderConfig {
+    /// Output width in pixels; height is derived to preserve aspect ratio.
+    pub target_width: c_int,
+    /// Upper bound on output height in pixels; when the aspect-derived height
+    /// exceeds it, width and height are scaled down proportionally.  Zero
+    /// means unbounded.
+    pub maximum_height: c_int,
+    /// Clockwise rotation in degrees, one of `0`, `90`, `180`, `270`.
+    pub rotation: c_int,
+    /// Render page annotations.
+    pub render_annotations: bool,
+    /// Render interactive form fields.
+    pub render_forms: bool,
+    /// Background color as packed RGBA (`0xRRGGBBAA`).  A non-opaque color is
+    /// filled before the page is composited.
+    pub background_color: u32,
+    /// Layout of the returned [`PdfiumBitmap`].  The wrapper allocates the
+    /// PDFium bitmap with the matching `FPDFBitmap_*` constant.
+    pub pixel_format: PdfiumPixelFormat,
 }
 
 extern "C" {
@@ -48,6 +95,37 @@ extern "C" {
     /// Returns an opaque document handle, or null on failure.
     pub fn pdfium_load_document(path: *const c_char) -> *mut c_void;
 
+    /// Load a (possibly encrypted) PDF document from a file path.
+    ///
+    /// `password` is a null-terminated UTF-8 string, or null for no password.
+    /// Returns an opaque document handle, or null on failure.
+    ///
+    /// When `out_wrong_password` is non-null it is set to a non-zero value
+    /// when the load failed specifically because the supplied password was
+    /// missing or incorrect, letting callers distinguish that case from a
+    /// malformed file.
+    pub fn pdfium_load_document_with_password(
+        path: *const c_char,
+        password: *const c_char,
+        out_wrong_password: *mut c_int,
+    ) -> *mut c_void;
+
+    /// Load a (possibly encrypted) PDF document from a memory buffer.
+    ///
+    /// The wrapper copies `len` bytes from `data` internally, so the caller
+    /// may free the buffer as soon as this function returns; the copy is kept
+    /// alive until [`pdfium_close_document`].  `password` is a null-terminated
+    /// UTF-8 string, or null for no password.
+    ///
+    /// See [`pdfium_load_document_with_password`] for the `out_wrong_password`
+    /// out-param semantics.  Returns null on failure.
+    pub fn pdfium_load_document_from_memory(
+        data: *const c_uchar,
+        len: usize,
+        password: *const c_char,
+        out_wrong_password: *mut c_int,
+    ) -> *mut c_void;
+
     /// Close a previously loaded document and free its resources.
     pub fn pdfium_close_document(doc: *mut c_void);
 
@@ -64,6 +142,89 @@ extern "C" {
         target_width: c_int,
     ) -> *mut PdfiumBitmap;
 
+    /// Render a single page using a [`PdfiumRenderConfig`].
+    ///
+    /// Extends [`pdfium_render_page`] with rotation, a height cap, annotation
+    /// and form rendering, and a fill color.  Returns a pointer to a
+    /// [`PdfiumBitmap`], or null on failure.  The caller must free it with
+    /// [`pdfium_free_bitmap`].
+    pub fn pdfium_render_page_ex(
+        doc: *mut c_void,
+        page_index: c_int,
+        config: *const PdfiumRenderConfig,
+    ) -> *mut PdfiumBitmap;
+
     /// Free a bitmap previously returned by [`pdfium_render_page`].
     pub fn pdfium_free_bitmap(bitmap: *mut c_void);
+
+    /// Extract all text on a page as a heap-allocated, null-terminated UTF-8
+    /// string.
+    ///
+    /// The wrapper drives `FPDFText_LoadPage`/`FPDFText_GetText` and converts
+    /// PDFium's UTF-16LE output to UTF-8.  Returns null on failure.  The
+    /// caller must free the result with [`pdfium_free_string`].
+    pub fn pdfium_get_page_text_utf8(doc: *mut c_void, page_index: c_int) -> *mut c_char;
+
+    /// Count the characters on a page (via `FPDFText_CountChars`).
+    ///
+    /// Returns the character count, or a negative value on failure.
+    pub fn pdfium_count_page_chars(doc: *mut c_void, page_index: c_int) -> c_int;
+
+    /// Extract the text intersecting the rectangle `(left, top, right, bottom)`
+    /// — in PDFium page coordinates — as a heap-allocated, null-terminated
+    /// UTF-8 string.
+    ///
+    /// Returns null on failure.  The caller must free the result with
+    /// [`pdfium_free_string`].
+    pub fn pdfium_get_text_in_rect(
+        doc: *mut c_void,
+        page_index: c_int,
+        left: f64,
+        top: f64,
+        right: f64,
+        bottom: f64,
+    ) -> *mut c_char;
+
+    /// Free a string previously returned by one of the text-extraction
+    /// functions (e.g. [`pdfium_get_page_text_utf8`]).
+    pub fn pdfium_free_string(s: *mut c_char);
+
+    /// Count the pages in a document (via `FPDF_GetPageCount`).
+    ///
+    /// Returns the page count, or a negative value on failure.
+    pub fn pdfium_count_pages(doc: *mut c_void) -> c_int;
+
+    /// Write a page's size in points into `width_pts` / `height_pts`.
+    ///
+    /// Returns non-zero on success, zero on failure.
+    pub fn pdfium_get_page_size(
+        doc: *mut c_void,
+        page_index: c_int,
+        width_pts: *mut f32,
+        height_pts: *mut f32,
+    ) -> c_int;
+
+    /// Return the document's first top-level bookmark, or null when the
+    /// document has no outline.
+    pub fn pdfium_get_first_bookmark(doc: *mut c_void) -> *mut c_void;
+
+    /// Return the next sibling of `bookmark`, or null at the end of the level.
+    pub fn pdfium_get_next_bookmark(doc: *mut c_void, bookmark: *mut c_void) -> *mut c_void;
+
+    /// Return the first child of `bookmark`, or null when it is a leaf.
+    pub fn pdfium_get_first_child_bookmark(doc: *mut c_void, bookmark: *mut c_void) -> *mut c_void;
+
+    /// Return a bookmark's title as a heap-allocated, null-terminated UTF-8
+    /// string (converted from PDFium's UTF-16LE).
+    ///
+    /// Returns null on failure.  The caller must free the result with
+    /// [`pdfium_free_string`].
+    pub fn pdfium_get_bookmark_title_utf8(bookmark: *mut c_void) -> *mut c_char;
+
+    /// Resolve a bookmark's destination to a zero-based page index (via
+    /// `FPDFDest_GetDestPageIndex`).
+    ///
+    /// Returns -1 when the bookmark targets an external/URI action or cannot
+    /// be resolved.
+    pub fn pdfium_get_bookmark_page_index(doc: *mut c_void, bookmark: *mut c_void) -> c_int;
 }